@@ -88,12 +88,12 @@
 )]
 #![forbid(unsafe_code)]
 
-use core::{marker::PhantomData, ops::Deref};
+use core::{marker::PhantomData, ops::Deref, str::FromStr};
 pub use uuid;
 use uuid::Uuid;
 
 /// Errors which might occur when using [`Id`].
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub enum Error {
     /// Attempted to create an [`Id<T, Version>`] where the generic [`Uuid`] being converted from
     /// was of a different Uuid version, than the one specified in the [`Id`] type.
@@ -103,15 +103,45 @@ pub enum Error {
         /// Actual version of the provided [`Uuid`]
         actual: usize,
     },
+    /// Attempted to strictly create an [`Id<T, Version>`] from a generic [`Uuid`]
+    /// whose [`Variant`] was not [`Variant::Rfc4122`].
+    WrongVariant {
+        /// Expected variant, always [`Variant::Rfc4122`] for the strict constructors
+        expected: Variant,
+        /// Actual variant of the provided [`Uuid`]
+        actual: Variant,
+    },
+    /// Failed to parse a string as a [`Uuid`] before version validation could run.
+    InvalidUuid(uuid::Error),
+    /// Attempted to create an [`Id<T, Nil>`] from a generic [`Uuid`] that was
+    /// not the nil (all-zero) value.
+    NotNil,
+    /// Attempted to create an [`Id<T, Max>`] from a generic [`Uuid`] that was
+    /// not the max (all-one) value.
+    NotMax,
+}
+
+/// Variant of a [`Uuid`], encoded in the top bits of byte 8 of its layout.
+///
+/// Unlike the version field, the variant behaves more like a mask: most
+/// UUIDs in the wild are [`Variant::Rfc4122`], with the other variants
+/// reserved for backwards compatibility with older GUID layouts or future
+/// expansion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Variant {
+    /// Reserved, for backward compatibility with the NCS.
+    Ncs,
+    /// The variant specified in RFC 4122. The vast majority of UUIDs use this variant.
+    Rfc4122,
+    /// Reserved, for backward compatibility with Microsoft GUIDs.
+    Microsoft,
+    /// Reserved for future expansion.
+    Future,
 }
 
 /// Typed wrapper around a [`Uuid`], supports same versions of Uuid as the `uuid` crate trough the `Version` parameter.
 #[derive(Eq, PartialOrd, Ord)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct Id<T, Version>(
-    Uuid,
-    #[cfg_attr(feature = "serde", serde(skip))] PhantomData<(T, Version)>,
-);
+pub struct Id<T, Version>(Uuid, PhantomData<(T, Version)>);
 
 impl<T, Version> Copy for Id<T, Version> {}
 
@@ -153,6 +183,37 @@ impl<T, Version> Deref for Id<T, Version> {
     }
 }
 
+impl<T, Version> Id<T, Version> {
+    /// Returns `true` if the contained [`Uuid`] is the nil (all-zero) value.
+    ///
+    /// Most useful for checking whether an [`Id`] is still a sentinel value,
+    /// such as one constructed via [`Id::<T, Nil>::nil`], before it has been
+    /// assigned a real id.
+    pub fn is_nil(&self) -> bool {
+        self.0.is_nil()
+    }
+
+    /// Returns the [`Variant`] encoded in the contained [`Uuid`].
+    pub fn variant(&self) -> Variant {
+        match self.0.as_bytes()[8] {
+            byte if byte & 0x80 == 0x00 => Variant::Ncs,
+            byte if byte & 0xc0 == 0x80 => Variant::Rfc4122,
+            byte if byte & 0xe0 == 0xc0 => Variant::Microsoft,
+            _ => Variant::Future,
+        }
+    }
+
+    /// Consumes the [`Id`], returning the underlying 16 raw bytes of the [`Uuid`].
+    pub fn into_bytes(self) -> [u8; 16] {
+        self.0.into_bytes()
+    }
+
+    /// Returns a reference to the underlying 16 raw bytes of the [`Uuid`].
+    pub fn as_bytes(&self) -> &[u8; 16] {
+        self.0.as_bytes()
+    }
+}
+
 impl<T, Version> PartialEq<Id<T, Version>> for Id<T, Version> {
     fn eq(&self, other: &Id<T, Version>) -> bool {
         self.0 == other.0
@@ -165,6 +226,143 @@ impl<T, Version> PartialEq<Uuid> for Id<T, Version> {
     }
 }
 
+#[cfg(all(feature = "serde", not(feature = "serde-bytes")))]
+impl<T, Version> serde::Serialize for Id<T, Version> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde-bytes")]
+impl<T, Version> serde::Serialize for Id<T, Version> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_bytes(self.0.as_bytes())
+    }
+}
+
+#[cfg(all(feature = "serde", not(feature = "serde-bytes")))]
+impl<'de, T, Version: UuidVersion> serde::Deserialize<'de> for Id<T, Version> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let uuid = Uuid::deserialize(deserializer)?;
+        Self::from_generic_uuid(uuid).map_err(serde_error)
+    }
+}
+
+#[cfg(feature = "serde-bytes")]
+impl<'de, T, Version: UuidVersion> serde::Deserialize<'de> for Id<T, Version> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let bytes = <[u8; 16]>::deserialize(deserializer)?;
+        Self::from_generic_uuid(Uuid::from_bytes(bytes)).map_err(serde_error)
+    }
+}
+
+/// Turns an [`Error`] produced while coercing a deserialized [`Uuid`] into the
+/// matching `serde` error message.
+#[cfg(any(feature = "serde", feature = "serde-bytes"))]
+fn serde_error<E: serde::de::Error>(error: Error) -> E {
+    match error {
+        Error::WrongVersion { expected, actual } => {
+            E::custom(format_args!("expected UUID version {expected}, found {actual}"))
+        }
+        Error::WrongVariant { expected, actual } => E::custom(format_args!(
+            "expected UUID variant {expected:?}, found {actual:?}"
+        )),
+        Error::InvalidUuid(err) => E::custom(format_args!("{err}")),
+        Error::NotNil => E::custom("expected the nil UUID"),
+        Error::NotMax => E::custom("expected the max UUID"),
+    }
+}
+
+/// Associates a marker type such as [`V1`] or [`V4`] with the UUID version
+/// number it represents.
+///
+/// This mirrors the discriminants of the `Version` enum in the `uuid` crate
+/// (`Nil` = 0, `Time` (v1) = 1, `Md5` (v3) = 3, `Random` (v4) = 4, `Sha1`
+/// (v5) = 5, ...), letting code that is generic over `Id<T, Version>` read
+/// the expected version at the type level, and letting [`Id::from_generic_uuid`]
+/// be implemented once for every version instead of once per module.
+pub trait UuidVersion {
+    /// The UUID version number denoted by this marker type.
+    const VERSION: usize;
+}
+
+impl<T, Version: UuidVersion> Id<T, Version> {
+    /// Attempt to coerce a generic [`Uuid`] into a typed [`Id`].
+    ///
+    /// Returns `Err(Error::WrongVersion)` if the generic Uuid version
+    /// does not match [`UuidVersion::VERSION`].
+    pub fn from_generic_uuid(uuid: Uuid) -> Result<Self, Error> {
+        if uuid.get_version_num() == Version::VERSION {
+            Ok(Id(uuid, PhantomData::default()))
+        } else {
+            Err(Error::WrongVersion {
+                expected: Version::VERSION,
+                actual: uuid.get_version_num(),
+            })
+        }
+    }
+
+    /// Like [`Id::from_generic_uuid`], but additionally rejects ids whose
+    /// [`Variant`] is not [`Variant::Rfc4122`].
+    ///
+    /// Use this when ingesting ids from sources that might hand back
+    /// foreign or Microsoft-GUID blobs carrying a coincidentally valid
+    /// version nibble.
+    pub fn from_generic_uuid_strict(uuid: Uuid) -> Result<Self, Error> {
+        let id = Self::from_generic_uuid(uuid)?;
+        match id.variant() {
+            Variant::Rfc4122 => Ok(id),
+            actual => Err(Error::WrongVariant {
+                expected: Variant::Rfc4122,
+                actual,
+            }),
+        }
+    }
+}
+
+impl<T, Version: UuidVersion> TryFrom<[u8; 16]> for Id<T, Version> {
+    type Error = Error;
+
+    fn try_from(bytes: [u8; 16]) -> Result<Self, Self::Error> {
+        Self::from_generic_uuid(Uuid::from_bytes(bytes))
+    }
+}
+
+impl<T, Version: UuidVersion> TryFrom<Uuid> for Id<T, Version> {
+    type Error = Error;
+
+    fn try_from(uuid: Uuid) -> Result<Self, Self::Error> {
+        Self::from_generic_uuid(uuid)
+    }
+}
+
+impl<T, Version: UuidVersion> FromStr for Id<T, Version> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let uuid = Uuid::parse_str(s).map_err(Error::InvalidUuid)?;
+        Self::from_generic_uuid(uuid)
+    }
+}
+
+#[cfg(feature = "nil")]
+pub use nil::Nil;
+
+#[cfg(feature = "max")]
+pub use max::Max;
+
 #[cfg(feature = "v1")]
 pub use v1::V1;
 
@@ -186,9 +384,92 @@ pub use v7::V7;
 #[cfg(all(unstable_uuid, feature = "v8"))]
 pub use v8::V8;
 
+#[cfg(feature = "nil")]
+mod nil {
+    use crate::{Error, Id};
+    use core::marker::PhantomData;
+    use uuid::Uuid;
+
+    /// Denotes that the contained Uuid is the nil (all-zero) sentinel value.
+    #[derive(Debug)]
+    pub struct Nil;
+
+    impl<T> Id<T, Nil> {
+        /// Construct the nil [`Id`], a type-safe placeholder for "no id yet".
+        pub const fn nil() -> Self {
+            Self(Uuid::nil(), PhantomData)
+        }
+
+        /// Attempt to coerce a generic [`Uuid`] into a typed nil [`Id`]
+        ///
+        /// Returns `Err(Error::NotNil)` if the generic Uuid is not nil
+        pub fn from_generic_uuid(uuid: Uuid) -> Result<Self, Error> {
+            if uuid.is_nil() {
+                Ok(Id(uuid, PhantomData::default()))
+            } else {
+                Err(Error::NotNil)
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::Nil;
+        use crate::Id;
+
+        #[test]
+        fn nil() {
+            let id = Id::<u32, Nil>::nil();
+            assert!(id.is_nil());
+        }
+    }
+}
+
+#[cfg(feature = "max")]
+mod max {
+    use crate::{Error, Id};
+    use core::marker::PhantomData;
+    use uuid::Uuid;
+
+    /// Denotes that the contained Uuid is the max (all-one) sentinel value.
+    #[derive(Debug)]
+    pub struct Max;
+
+    impl<T> Id<T, Max> {
+        /// Construct the max [`Id`], a type-safe placeholder for an
+        /// always-greater-than sentinel value.
+        pub const fn max() -> Self {
+            Self(Uuid::max(), PhantomData)
+        }
+
+        /// Attempt to coerce a generic [`Uuid`] into a typed max [`Id`]
+        ///
+        /// Returns `Err(Error::NotMax)` if the generic Uuid is not max
+        pub fn from_generic_uuid(uuid: Uuid) -> Result<Self, Error> {
+            if uuid.is_max() {
+                Ok(Id(uuid, PhantomData::default()))
+            } else {
+                Err(Error::NotMax)
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::Max;
+        use crate::Id;
+
+        #[test]
+        fn max() {
+            let id = Id::<u32, Max>::max();
+            assert!(id.as_ref().is_max());
+        }
+    }
+}
+
 #[cfg(feature = "v1")]
 mod v1 {
-    use crate::{Error, Id};
+    use crate::{Id, UuidVersion};
     use core::marker::PhantomData;
     use uuid::{Timestamp, Uuid};
 
@@ -196,27 +477,16 @@ mod v1 {
     #[derive(Debug)]
     pub struct V1;
 
+    impl UuidVersion for V1 {
+        const VERSION: usize = 1;
+    }
+
     impl<T> Id<T, V1> {
         /// Construct a new typed v1 Uuid
         #[allow(clippy::new_without_default)]
         pub fn new(ts: Timestamp, node_id: &[u8; 6]) -> Self {
             Self(Uuid::new_v1(ts, node_id), PhantomData::default())
         }
-
-        /// Attempt to coerce a generic [`Uuid`] into a typed [`Id`]
-        ///
-        /// Returns `Err(Error::WrongVersion)` if the generic Uuid version
-        /// is not v1
-        pub fn from_generic_uuid(uuid: Uuid) -> Result<Self, Error> {
-            if uuid.get_version_num() == 1 {
-                Ok(Id(uuid, PhantomData::default()))
-            } else {
-                Err(Error::WrongVersion {
-                    expected: 1,
-                    actual: uuid.get_version_num(),
-                })
-            }
-        }
     }
 
     #[cfg(test)]
@@ -235,34 +505,23 @@ mod v1 {
 
 #[cfg(feature = "v3")]
 mod v3 {
-    use crate::{Error, Id, Uuid};
+    use crate::{Id, Uuid, UuidVersion};
     use core::marker::PhantomData;
 
     /// Denotes that the contained Uuid is of type V3
     #[derive(Debug)]
     pub struct V3;
 
+    impl UuidVersion for V3 {
+        const VERSION: usize = 3;
+    }
+
     impl<T> Id<T, V3> {
         /// Construct a new typed v3 Uuid
         #[allow(clippy::new_without_default)]
         pub fn new(namespace: &Uuid, name: &[u8]) -> Self {
             Self(Uuid::new_v3(namespace, name), PhantomData::default())
         }
-
-        /// Attempt to coerce a generic [`Uuid`] into a typed [`Id`]
-        ///
-        /// Returns `Err(Error::WrongVersion)` if the generic Uuid version
-        /// is not v3
-        pub fn from_generic_uuid(uuid: Uuid) -> Result<Self, Error> {
-            if uuid.get_version_num() == 3 {
-                Ok(Id(uuid, PhantomData::default()))
-            } else {
-                Err(Error::WrongVersion {
-                    expected: 3,
-                    actual: uuid.get_version_num(),
-                })
-            }
-        }
     }
 
     #[cfg(test)]
@@ -280,34 +539,23 @@ mod v3 {
 
 #[cfg(feature = "v4")]
 mod v4 {
-    use crate::{Error, Id, Uuid};
+    use crate::{Id, Uuid, UuidVersion};
     use core::marker::PhantomData;
 
     /// Denotes that the contained Uuid is of type V4
     #[derive(Debug)]
     pub struct V4;
 
+    impl UuidVersion for V4 {
+        const VERSION: usize = 4;
+    }
+
     impl<T> Id<T, V4> {
         /// Construct a new typed v4 Uuid
         #[allow(clippy::new_without_default)]
         pub fn new() -> Self {
             Self(Uuid::new_v4(), PhantomData::default())
         }
-
-        /// Attempt to coerce a generic [`Uuid`] into a typed [`Id`]
-        ///
-        /// Returns `Err(Error::WrongVersion)` if the generic Uuid version
-        /// is not v4
-        pub fn from_generic_uuid(uuid: Uuid) -> Result<Self, Error> {
-            if uuid.get_version_num() == 4 {
-                Ok(Id(uuid, PhantomData::default()))
-            } else {
-                Err(Error::WrongVersion {
-                    expected: 4,
-                    actual: uuid.get_version_num(),
-                })
-            }
-        }
     }
 
     #[cfg(test)]
@@ -319,39 +567,121 @@ mod v4 {
         fn new() {
             let _ = Id::<u32, V4>::new();
         }
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn deserialize_rejects_wrong_version() {
+            let v1 = uuid::Uuid::parse_str("a8098c1a-f86e-11da-bd1a-00112444be1e").unwrap();
+            let json = serde_json::to_string(&v1).unwrap();
+
+            let result: Result<Id<u32, V4>, _> = serde_json::from_str(&json);
+            assert!(result.is_err());
+        }
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn serde_round_trip() {
+            let id = Id::<u32, V4>::new();
+            let json = serde_json::to_string(&id).unwrap();
+
+            let deserialized: Id<u32, V4> = serde_json::from_str(&json).unwrap();
+            assert_eq!(id, deserialized);
+        }
+
+        #[cfg(feature = "serde-bytes")]
+        #[test]
+        fn serde_bytes_round_trip() {
+            let id = Id::<u32, V4>::new();
+            let json = serde_json::to_string(&id).unwrap();
+
+            let deserialized: Id<u32, V4> = serde_json::from_str(&json).unwrap();
+            assert_eq!(id, deserialized);
+        }
+
+        #[test]
+        fn bytes_round_trip() {
+            let id = Id::<u32, V4>::new();
+            let bytes = id.into_bytes();
+
+            let roundtripped = Id::<u32, V4>::try_from(bytes).unwrap();
+            assert_eq!(id, roundtripped);
+        }
+
+        #[test]
+        fn from_str_round_trip() {
+            let id = Id::<u32, V4>::new();
+
+            let mut buf = [0u8; 36];
+            let formatted = id.as_ref().hyphenated().encode_lower(&mut buf);
+
+            let parsed: Id<u32, V4> = formatted.parse().unwrap();
+            assert_eq!(id, parsed);
+        }
+
+        #[test]
+        fn from_str_rejects_wrong_version() {
+            let result = "a8098c1a-f86e-11da-bd1a-00112444be1e".parse::<Id<u32, V4>>();
+            assert!(result.is_err());
+        }
+
+        #[test]
+        fn variant_decodes_rfc4122() {
+            let id = Id::<u32, V4>::new();
+            assert_eq!(id.variant(), crate::Variant::Rfc4122);
+        }
+
+        #[test]
+        fn variant_decodes_microsoft() {
+            // Version nibble set to 4, but the variant bits (byte 8, top 3
+            // bits `110`) marked as Microsoft instead of Rfc4122.
+            let mut bytes = [0u8; 16];
+            bytes[6] = 0x40;
+            bytes[8] = 0xc0;
+            let uuid = uuid::Uuid::from_bytes(bytes);
+
+            let id = Id::<u32, V4>::from_generic_uuid(uuid).unwrap();
+            assert_eq!(id.variant(), crate::Variant::Microsoft);
+        }
+
+        #[test]
+        fn from_generic_uuid_strict_accepts_rfc4122() {
+            let id = Id::<u32, V4>::new();
+            let strict = Id::<u32, V4>::from_generic_uuid_strict(*id.as_ref());
+            assert_eq!(strict.unwrap(), id);
+        }
+
+        #[test]
+        fn from_generic_uuid_strict_rejects_non_rfc4122() {
+            let mut bytes = [0u8; 16];
+            bytes[6] = 0x40;
+            bytes[8] = 0xc0;
+            let uuid = uuid::Uuid::from_bytes(bytes);
+
+            let result = Id::<u32, V4>::from_generic_uuid_strict(uuid);
+            assert!(result.is_err());
+        }
     }
 }
 
 #[cfg(feature = "v5")]
 mod v5 {
-    use crate::{Error, Id, Uuid};
+    use crate::{Id, Uuid, UuidVersion};
     use core::marker::PhantomData;
 
     /// Denotes that the contained Uuid is of type V5
     #[derive(Debug)]
     pub struct V5;
 
+    impl UuidVersion for V5 {
+        const VERSION: usize = 5;
+    }
+
     impl<T> Id<T, V5> {
         /// Construct a new typed v5 Uuid
         #[allow(clippy::new_without_default)]
         pub fn new(namespace: &Uuid, name: &[u8]) -> Self {
             Self(Uuid::new_v5(namespace, name), PhantomData::default())
         }
-
-        /// Attempt to coerce a generic [`Uuid`] into a typed [`Id`]
-        ///
-        /// Returns `Err(Error::WrongVersion)` if the generic Uuid version
-        /// is not v5
-        pub fn from_generic_uuid(uuid: Uuid) -> Result<Self, Error> {
-            if uuid.get_version_num() == 5 {
-                Ok(Id(uuid, PhantomData::default()))
-            } else {
-                Err(Error::WrongVersion {
-                    expected: 5,
-                    actual: uuid.get_version_num(),
-                })
-            }
-        }
     }
 
     #[cfg(test)]
@@ -369,7 +699,7 @@ mod v5 {
 
 #[cfg(all(uuid_unstable, feature = "v6"))]
 mod v6 {
-    use crate::{Error, Id};
+    use crate::{Id, UuidVersion};
     use core::marker::PhantomData;
     use uuid::{Timestamp, Uuid};
 
@@ -377,33 +707,22 @@ mod v6 {
     #[derive(Debug)]
     pub struct V6;
 
+    impl UuidVersion for V6 {
+        const VERSION: usize = 6;
+    }
+
     impl<T> Id<T, V6> {
         /// Construct a new typed v6 Uuid
         #[allow(clippy::new_without_default)]
         pub fn new(ts: Timestamp, node_id: &[u8; 6]) -> Self {
             Self(Uuid::new_v1(ts, node_id), PhantomData::default())
         }
-
-        /// Attempt to coerce a generic [`Uuid`] into a typed [`Id`]
-        ///
-        /// Returns `Err(Error::WrongVersion)` if the generic Uuid version
-        /// is not v6
-        pub fn from_generic_uuid(uuid: Uuid) -> Result<Self, Error> {
-            if uuid.get_version_num() == 6 {
-                Ok(Id(uuid, PhantomData::default()))
-            } else {
-                Err(Error::WrongVersion {
-                    expected: 6,
-                    actual: uuid.get_version_num(),
-                })
-            }
-        }
     }
 }
 
 #[cfg(all(uuid_unstable, feature = "v7"))]
 mod v7 {
-    use crate::{Error, Id};
+    use crate::{Id, UuidVersion};
     use core::marker::PhantomData;
     use uuid::{Timestamp, Uuid};
 
@@ -411,67 +730,37 @@ mod v7 {
     #[derive(Debug)]
     pub struct V7;
 
+    impl UuidVersion for V7 {
+        const VERSION: usize = 7;
+    }
+
     impl<T> Id<T, V7> {
         /// Construct a new typed v7 Uuid
         #[allow(clippy::new_without_default)]
         pub fn new(ts: Timestamp) -> Self {
             Self(Uuid::new_v7(ts), PhantomData::default())
         }
-
-        /// Attempt to coerce a generic [`Uuid`] into a typed [`Id`]
-        ///
-        /// Returns `Err(Error::WrongVersion)` if the generic Uuid version
-        /// is not v7
-        pub fn from_generic_uuid(uuid: Uuid) -> Result<Self, Error> {
-            if uuid.get_version_num() == 7 {
-                Ok(Id(uuid, PhantomData::default()))
-            } else {
-                Err(Error::WrongVersion {
-                    expected: 7,
-                    actual: uuid.get_version_num(),
-                })
-            }
-        }
     }
 }
 
 #[cfg(all(uuid_unstable, feature = "v8"))]
 mod v8 {
-    use crate::{Error, Id, Uuid};
+    use crate::{Id, Uuid, UuidVersion};
     use core::marker::PhantomData;
 
     /// Denotes that the contained Uuid is of type V8
     #[derive(Debug)]
     pub struct V8;
 
+    impl UuidVersion for V8 {
+        const VERSION: usize = 8;
+    }
+
     impl<T> Id<T, V8> {
         /// Construct a new typed v8 Uuid
         #[allow(clippy::new_without_default)]
         pub fn new(buf: [u8; 16]) -> Self {
             Self(Uuid::new_v8(buf), PhantomData::default())
         }
-
-        /// Attempt to coerce a generic [`Uuid`] into a typed [`Id`]
-        ///
-        /// Returns `Err(Error::WrongVersion)` if the generic Uuid version
-        /// is not v8
-        pub fn from_generic_uuid(uuid: Uuid) -> Result<Self, Error> {
-            if uuid.get_version_num() == 8 {
-                Ok(Id(uuid, PhantomData::default()))
-            } else {
-                Err(Error::WrongVersion {
-                    expected: 8,
-                    actual: uuid.get_version_num(),
-                })
-            }
-        }
-    }
-}
-
-/*
-impl<T, Version: UntypedVersion> From<[u8; 16]> for Id<T, Version> {
-    fn from(value: [u8; 16]) -> Self {
-        Id::<T, Version>(Uuid::from_bytes(value), PhantomData::default())
     }
 }
-*/